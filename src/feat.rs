@@ -5,7 +5,9 @@ use csv::ByteRecord;
 use csv::ByteRecordIter;
 use csv::Error as CsvError;
 use csv::Reader as CsvReader;
+use csv::ReaderBuilder;
 
+use std::io;
 use std::ops::Index;
 use std::str::Utf8Error;
 
@@ -43,12 +45,16 @@ use std::str::Utf8Error;
 /// # }
 /// ```
 ///
+/// `FeatureReader` is generic over its source: the common case reads a `&[u8]` in memory and
+/// lends out the parsed record as a borrowing [`Features`], while [`Self::from_reader()`] accepts
+/// any [`std::io::Read`] (a file, a pipe, ...) and hands out owned records instead. See
+/// [`Self::record()`] for the streaming case.
 #[derive(Debug)]
-pub struct FeatureReader<'a> {
-    reader: CsvReader<&'a [u8]>,
+pub struct FeatureReader<R> {
+    reader: CsvReader<R>,
 }
 
-impl<'a> FeatureReader<'a> {
+impl<'a> FeatureReader<&'a [u8]> {
     /// Initializes with the given feature string.
     ///
     /// ```
@@ -75,11 +81,62 @@ impl<'a> FeatureReader<'a> {
         Self::from_features(node.features())
     }
 
-    /// Returns an [`IntoIterator`] of  [`Feature`].
+    /// Returns an [`IntoIterator`] of  [`Feature`], borrowing from the record this reader parsed
+    /// out of its in-memory feature string.
     pub fn features(&mut self) -> Result<Features<'_>, CsvError> {
         let record = self.reader.byte_headers()?;
         Ok(Features { record })
     }
+
+    /// Same as [`self.features()?.deserialize()`](Features::deserialize()).
+    #[cfg(feature = "serde")]
+    pub fn deserialize<T>(&mut self) -> Result<T, CsvError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        self.features()?.deserialize()
+    }
+}
+
+impl<R: io::Read> FeatureReader<R> {
+    /// Initializes with a streaming feature/CSV source, e.g. a file or a pipe. Unlike
+    /// [`Self::from_features()`], records are read with [`Self::record()`], which returns an
+    /// owned [`ByteRecord`] instead of a borrowing [`Features`].
+    pub fn from_reader(reader: R) -> Self {
+        Self {
+            // Every line is a data record here (there is no header row to treat specially), but
+            // leaving `flexible` at its default `false` still makes the reader track the first
+            // record's field count and flag later records of a different length -- which is
+            // the signal `record()` uses to report a short (e.g. truncated) record as
+            // `UnexpectedEof`.
+            reader: ReaderBuilder::new().has_headers(false).from_reader(reader),
+        }
+    }
+
+    /// Reads the next feature record from the stream as an owned [`ByteRecord`].
+    ///
+    /// Returns `Ok(None)` at a clean end of the stream. A record that has fewer fields than the
+    /// first record read (e.g. a dictionary dump truncated mid-row, such as in an unterminated
+    /// quoted field) is reported as [`io::ErrorKind::UnexpectedEof`] instead of silently handing
+    /// back the short record, wherever in the stream it occurs; a record with more fields is
+    /// reported as [`io::ErrorKind::InvalidData`].
+    pub fn record(&mut self) -> io::Result<Option<ByteRecord>> {
+        let mut record = ByteRecord::new();
+
+        let had_record = self.reader.read_byte_record(&mut record).map_err(|e| match e.kind() {
+            csv::ErrorKind::Io(io_err) => io::Error::new(io_err.kind(), e.to_string()),
+            csv::ErrorKind::UnequalLengths { expected_len, len, .. } if len < expected_len => {
+                io::Error::new(io::ErrorKind::UnexpectedEof, e.to_string())
+            }
+            _ => io::Error::new(io::ErrorKind::InvalidData, e.to_string()),
+        })?;
+
+        if had_record {
+            Ok(Some(record))
+        } else {
+            Ok(None)
+        }
+    }
 }
 
 /// [`IntoIterator`] of [`Feature`]. This is a wrapper of [`&csv::ByteRecord`](csv::ByteRecord).
@@ -158,6 +215,47 @@ impl<'a> Features<'a> {
     pub fn iter(&self) -> impl Iterator<Item = Feature<'_>> {
         self.record.iter().map(|inner| Feature { inner })
     }
+
+    /// Deserializes `self` into a user-provided struct `T`, mapping each field onto the
+    /// corresponding CSV column in order. MeCab's `*` placeholder ("unspecified") is treated as
+    /// an empty field, so it deserializes to `None` for `Option<_>` fields of `T`. Returns an
+    /// error (via [`csv::ByteRecord::deserialize()`]) if the record has fewer columns than `T`
+    /// has fields; columns beyond the ones `T` reads are ignored, not reported.
+    ///
+    /// ```
+    /// use mecab_wrapper::FeatureReader;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct IpadicFeature {
+    ///     pos: String,
+    ///     pos_sub1: Option<String>,
+    ///     pos_sub2: Option<String>,
+    ///     pos_sub3: Option<String>,
+    ///     conjugation_type: Option<String>,
+    ///     conjugation_form: Option<String>,
+    ///     base_form: Option<String>,
+    ///     reading: Option<String>,
+    ///     pronunciation: Option<String>,
+    /// }
+    ///
+    /// let mut reader = FeatureReader::from_features(b"\xe5\x90\x8d\xe8\xa9\x9e,*,*,*,*,*,*,*,*");
+    /// let feats = reader.features().unwrap();
+    /// let feat: IpadicFeature = feats.deserialize().unwrap();
+    /// assert_eq!(feat.pos_sub1, None);
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn deserialize<T>(&self) -> Result<T, CsvError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let normalized: ByteRecord = self
+            .record
+            .iter()
+            .map(|field| if field == b"*" { &b""[..] } else { field })
+            .collect();
+        normalized.deserialize(None)
+    }
 }
 
 impl Index<usize> for Features<'_> {
@@ -1,15 +1,30 @@
 mod dictionary_info;
 pub use dictionary_info::DictionaryInfo;
+pub use dictionary_info::DictionaryInfoIter;
 pub use dictionary_info::DictionaryType;
 
 mod model;
 pub use model::Model;
+pub use model::ModelError;
+
+mod model_args;
+pub use model_args::ModelArgs;
+pub use model_args::NulError;
+pub use model_args::OptionKey;
+pub use model_args::TryModelArgs;
+
+mod model_builder;
+pub use model_builder::ModelBuilder;
+pub use model_builder::ModelBuilderError;
 
 mod tagger;
 pub use tagger::Tagger;
 
 mod lattice;
+pub use lattice::ConstrainError;
+pub use lattice::ConstraintSpan;
 pub use lattice::Lattice;
+pub use lattice::NBestIter;
 
 mod node;
 pub use node::Attribute;
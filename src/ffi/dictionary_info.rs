@@ -2,6 +2,7 @@ use libc::{c_char, c_int, c_uint, c_ushort};
 
 use std::ffi::CStr;
 use std::fmt;
+use std::iter::FusedIterator;
 
 /// Dictionary type. This is a return value of [`DictionaryInfo::dictionary_type()`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -69,8 +70,33 @@ impl DictionaryInfo {
     pub fn next(&self) -> Option<&Self> {
         unsafe { self.next.as_ref() }
     }
+
+    /// Returns an iterator over `self` and the dictionaries chained after it, via
+    /// [`DictionaryInfo::next()`].
+    pub fn iter(&self) -> DictionaryInfoIter<'_> {
+        DictionaryInfoIter { info: Some(self) }
+    }
+}
+
+/// Iterates a dictionary chain, via [`DictionaryInfo::next()`].
+///
+/// Obtained from [`DictionaryInfo::iter()`].
+pub struct DictionaryInfoIter<'a> {
+    info: Option<&'a DictionaryInfo>,
 }
 
+impl<'a> Iterator for DictionaryInfoIter<'a> {
+    type Item = &'a DictionaryInfo;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let info = self.info?;
+        self.info = info.next();
+        Some(info)
+    }
+}
+
+impl<'a> FusedIterator for DictionaryInfoIter<'a> {}
+
 impl fmt::Debug for DictionaryInfo {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("DictionaryInfo")
@@ -8,6 +8,7 @@ use libc::c_void;
 type VoidPtr = *mut c_void;
 
 use std::ffi::CStr;
+use std::fmt;
 use std::marker::PhantomData;
 use std::ops::ControlFlow;
 use std::str::Utf8Error;
@@ -86,6 +87,38 @@ pub enum Boundary {
     InsideToken,
 }
 
+/// A forced token span passed to [`Lattice::constrain()`].
+///
+/// `begin` and `end` are byte offsets into the constrained sentence, and must fall on UTF-8 char
+/// boundaries. `feature` optionally pins the span's parsed feature string (e.g. to force a POS
+/// for a known named entity).
+#[derive(Debug, Clone, Copy)]
+pub struct ConstraintSpan<'a> {
+    pub begin: usize,
+    pub end: usize,
+    pub feature: Option<&'a CStr>,
+}
+
+/// Error returned by [`Lattice::constrain()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConstrainError {
+    /// A span's `begin` or `end` does not fall on a UTF-8 char boundary of the sentence.
+    NotCharBoundary(usize),
+    /// Two spans overlap.
+    Overlapping,
+}
+
+impl fmt::Display for ConstrainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotCharBoundary(pos) => write!(f, "byte offset {pos} is not a char boundary"),
+            Self::Overlapping => write!(f, "spans overlap"),
+        }
+    }
+}
+
+impl std::error::Error for ConstrainError {}
+
 pub struct Lattice<'a> {
     void_lattice: VoidPtr,
     phantom: PhantomData<&'a ()>,
@@ -169,6 +202,12 @@ impl<'a> Lattice<'a> {
         lattice_to_string_alloc(self.void_lattice, buf.as_mut_ptr() as _, buf.len());
     }
 
+    /// Safe, owned counterpart of [`Lattice::to_str()`]. Copies the result into a freshly
+    /// allocated [`String`] instead of borrowing from `self`, at the cost of the extra copy.
+    pub fn to_string_owned(&self) -> Result<String, Utf8Error> {
+        self.to_str().map(str::to_owned)
+    }
+
     pub fn nbest_to_bytes(&self, n: usize) -> &[u8] {
         unsafe {
             let s = nbest_string(self.void_lattice, n);
@@ -193,6 +232,12 @@ impl<'a> Lattice<'a> {
         nbest_string_alloc(self.void_lattice, n, buf.as_mut_ptr() as _, buf.len());
     }
 
+    /// Safe, owned counterpart of [`Lattice::nbest_to_str()`]. Copies the result into a freshly
+    /// allocated [`String`] instead of borrowing from `self`, at the cost of the extra copy.
+    pub fn nbest_to_string_owned(&self, n: usize) -> Result<String, Utf8Error> {
+        self.nbest_to_str(n).map(str::to_owned)
+    }
+
     pub fn node_to_bytes<'b>(&'b self, node: &'b Node) -> &[u8] {
         unsafe {
             let s = node_string(self.void_lattice, node as *const Node as _);
@@ -222,6 +267,13 @@ impl<'a> Lattice<'a> {
         );
     }
 
+    /// Safe, owned counterpart of [`Lattice::node_to_str()`]. Copies the result into a freshly
+    /// allocated [`String`] instead of borrowing from `self` and `node`, at the cost of the extra
+    /// copy.
+    pub fn node_to_string_owned(&self, node: &Node) -> Result<String, Utf8Error> {
+        self.node_to_str(node).map(str::to_owned)
+    }
+
     pub fn bos_node(&self) -> Option<&Node> {
         unsafe {
             let node = bos_node(self.void_lattice);
@@ -283,6 +335,25 @@ impl<'a> Lattice<'a> {
         }
     }
 
+    /// Returns an [`NBestIter`] for walking successive N-best paths, each as a [`NodeIter`]
+    /// starting from [`Lattice::bos_node()`].
+    ///
+    /// [`RequestType::N_BEST`] must have been set (via [`Lattice::set_request_type()`] or
+    /// [`Lattice::add_request_type()`]) before parsing; otherwise [`NBestIter::next()`] always
+    /// returns `None`. See [`NBestIter`] for why it is not a [`std::iter::Iterator`].
+    pub fn iter_nbest(&mut self) -> NBestIter<'_> {
+        let enabled = self.get_request_type().contains(RequestType::N_BEST);
+        NBestIter {
+            void_lattice: self.void_lattice,
+            state: if enabled {
+                NBestState::NotStarted
+            } else {
+                NBestState::Disabled
+            },
+            phantom: PhantomData,
+        }
+    }
+
     pub fn norm_factor(&self) -> f64 {
         unsafe { lattice_norm_factor(self.void_lattice) }
     }
@@ -353,6 +424,67 @@ impl<'a> Lattice<'a> {
         }
     }
 
+    /// Builds a standalone [`Lattice`] constrained to the given token `spans`, for
+    /// dictionary-guided segmentation (e.g. forcing a known named entity to stay as a single
+    /// token).
+    ///
+    /// This sets `sentence`, enables [`RequestType::PARTIAL`], marks each span's `begin`/`end` as
+    /// [`Boundary::Token`] and its interior bytes as [`Boundary::InsideToken`], and applies
+    /// [`Lattice::set_feature_constraint()`] for spans that pin a feature.
+    ///
+    /// Returns [`ConstrainError::NotCharBoundary`] if a span's `begin` or `end` does not fall on
+    /// a UTF-8 char boundary of `sentence`, or [`ConstrainError::Overlapping`] if two spans
+    /// overlap.
+    ///
+    /// The returned lattice borrows `sentence` for `'a`: [`Lattice::set_sentence()`] stores a
+    /// pointer into `sentence`'s backing buffer rather than copying it, so the lattice must not
+    /// outlive the string it was built from.
+    pub fn constrain(
+        sentence: &'a str,
+        spans: &[ConstraintSpan<'_>],
+    ) -> Result<Self, ConstrainError> {
+        let mut sorted: Vec<&ConstraintSpan<'_>> = spans.iter().collect();
+        sorted.sort_by_key(|span| span.begin);
+
+        let mut prev_end = 0;
+        for span in &sorted {
+            if !sentence.is_char_boundary(span.begin) {
+                return Err(ConstrainError::NotCharBoundary(span.begin));
+            }
+            if !sentence.is_char_boundary(span.end) {
+                return Err(ConstrainError::NotCharBoundary(span.end));
+            }
+            if span.begin < prev_end {
+                return Err(ConstrainError::Overlapping);
+            }
+            // `.max()` keeps the watermark at the furthest end seen so far even after an
+            // inverted span (`begin > end`), which covers no bytes and is skipped below.
+            prev_end = prev_end.max(span.end);
+        }
+
+        let mut lattice = Self::new();
+        lattice.set_sentence(sentence);
+        lattice.add_request_type(RequestType::PARTIAL);
+
+        for span in spans {
+            if span.begin >= span.end {
+                continue;
+            }
+
+            lattice.set_boundary_constraint(span.begin, Boundary::Token);
+            for pos in (span.begin + 1)..span.end {
+                lattice.set_boundary_constraint(pos, Boundary::InsideToken);
+            }
+            lattice.set_boundary_constraint(span.end, Boundary::Token);
+
+            if let Some(feature) = span.feature {
+                lattice.set_feature_constraint(span.begin, span.end, feature);
+            }
+        }
+
+        Ok(lattice)
+    }
+
     pub fn set_result(&mut self, result: &CStr) {
         unsafe {
             lattice_set_result(self.void_lattice, result.as_ptr());
@@ -398,3 +530,48 @@ impl Drop for Lattice<'_> {
         }
     }
 }
+
+enum NBestState {
+    Disabled,
+    NotStarted,
+    Started,
+}
+
+/// Walks successive N-best paths, each a [`NodeIter`] starting from the lattice's BOS node.
+///
+/// Obtained from [`Lattice::iter_nbest()`].
+///
+/// This is deliberately *not* a [`std::iter::Iterator`]: each path is re-walked in place by
+/// mutating the same underlying lattice buffer, so a [`NodeIter`] (and every [`Node`] reached
+/// through it) borrowed from one call is invalidated as soon as [`Self::next()`] is called again.
+/// `Iterator::next()` takes `&mut self` per call but its `Item` type is fixed when the iterator
+/// is created, so a real `Iterator` impl would let safe code hold two paths' nodes live at once
+/// while the second call mutates the first path's nodes out from under it. Tying `next()`'s
+/// return value to the `&mut self` borrow of *that specific call* (as done here) makes that
+/// pattern a borrow-check error instead.
+pub struct NBestIter<'a> {
+    void_lattice: VoidPtr,
+    state: NBestState,
+    phantom: PhantomData<&'a mut ()>,
+}
+
+impl<'a> NBestIter<'a> {
+    /// Advances to the next N-best path, if any.
+    ///
+    /// The returned [`NodeIter`] borrows `self` for the call, so it (and any [`Node`] reached
+    /// through it) cannot outlive the next call to `next()`.
+    pub fn next(&mut self) -> Option<NodeIter<'_>> {
+        match self.state {
+            NBestState::Disabled => return None,
+            NBestState::NotStarted => self.state = NBestState::Started,
+            NBestState::Started => unsafe {
+                if !next_lattice(self.void_lattice) {
+                    return None;
+                }
+            },
+        }
+
+        let node = unsafe { (bos_node(self.void_lattice) as *const Node).as_ref() }?;
+        Some(NodeIter::from_node(node))
+    }
+}
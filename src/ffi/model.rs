@@ -1,4 +1,4 @@
-use super::{Attribute, DictionaryInfo, Lattice, ModelArgs, Node, Tagger};
+use super::{Attribute, DictionaryInfo, Lattice, ModelArgs, Node, NulError, Tagger, TryModelArgs};
 
 use libc::{c_char, c_int, c_ushort};
 
@@ -6,6 +6,7 @@ use libc::c_void;
 type VoidPtr = *mut c_void;
 
 use std::ffi::CStr;
+use std::fmt;
 use std::ptr::NonNull;
 use std::str::Utf8Error;
 
@@ -55,6 +56,19 @@ impl Model {
         Some(Self { void_model })
     }
 
+    /// Fallible counterpart of [`Model::new()`] for byte-string-like arguments (`&[u8]`, `&str`,
+    /// etc.) that may come from untrusted input.
+    ///
+    /// Unlike `new()`, an interior NUL in `arg` is reported as [`ModelError::Nul`] instead of
+    /// being passed on to [`CStr::from_bytes_with_nul_unchecked()`], which is undefined behavior
+    /// if the byte string contains a NUL before its intended end.
+    pub fn try_new<Arg: TryModelArgs>(arg: Arg) -> Result<Self, ModelError> {
+        let void_model = arg.try_create_model().map_err(ModelError::Nul)?;
+        NonNull::new(void_model)
+            .map(|void_model| Self { void_model })
+            .ok_or(ModelError::InitFailed)
+    }
+
     /// Dictionary information.
     pub fn dictionary_info(&self) -> &DictionaryInfo {
         unsafe {
@@ -142,3 +156,30 @@ impl Drop for Model {
         }
     }
 }
+
+impl fmt::Debug for Model {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Model").field("dictionary_info", self.dictionary_info()).finish()
+    }
+}
+
+/// Error returned by [`Model::try_new()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModelError {
+    /// The argument contains an interior NUL.
+    Nul(NulError),
+    /// The model failed to initialize. Use
+    /// [`global_error_str()`](crate::global_error_str()) to obtain the cause.
+    InitFailed,
+}
+
+impl fmt::Display for ModelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Nul(e) => write!(f, "{e}"),
+            Self::InitFailed => write!(f, "model failed to initialize"),
+        }
+    }
+}
+
+impl std::error::Error for ModelError {}
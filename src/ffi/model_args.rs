@@ -5,6 +5,7 @@ type VoidPtr = *mut c_void;
 
 use std::borrow::Cow;
 use std::ffi::{CStr, CString};
+use std::fmt;
 use std::iter::once;
 
 #[link(name = "cmecab")]
@@ -133,6 +134,109 @@ impl ModelArgs for &[u8] {
     }
 }
 
+/// An interior NUL was found in the bytes passed to [`Model::try_new()`](crate::Model::try_new())
+/// before the intended end of the string, so the argument was rejected rather than silently
+/// truncated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NulError {
+    /// Byte offset of the interior NUL.
+    pub position: usize,
+}
+
+impl fmt::Display for NulError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "interior NUL found at byte offset {}", self.position)
+    }
+}
+
+impl std::error::Error for NulError {}
+
+/// Scans `bytes` for the first NUL byte. If found at the last position, `bytes` is reused
+/// as-is (no allocation). If no NUL is found, exactly one is appended. If a NUL is found
+/// anywhere else, the byte string is rejected instead of being silently truncated by MeCab.
+fn checked_cstr(bytes: &[u8]) -> Result<Cow<'_, CStr>, NulError> {
+    match bytes.iter().position(|&b| b == 0) {
+        Some(position) if position + 1 == bytes.len() => {
+            let cstr = unsafe { CStr::from_bytes_with_nul_unchecked(bytes) };
+            Ok(Cow::Borrowed(cstr))
+        }
+        Some(position) => Err(NulError { position }),
+        None => {
+            let mut v = Vec::with_capacity(bytes.len() + 1);
+            v.extend_from_slice(bytes);
+            v.push(b'\0');
+            let cstring = unsafe { CString::from_vec_with_nul_unchecked(v) };
+            Ok(Cow::Owned(cstring))
+        }
+    }
+}
+
+/// Scans `bytes` for a NUL byte and rejects it with [`NulError`] if found, rather than silently
+/// truncating at it. Unlike [`checked_cstr()`], this assumes `bytes` is not already
+/// NUL-terminated (the common case for a path or option value assembled by the caller), and
+/// always appends the terminator itself.
+pub(crate) fn checked_cstring(bytes: &[u8]) -> Result<CString, NulError> {
+    match bytes.iter().position(|&b| b == 0) {
+        Some(position) => Err(NulError { position }),
+        None => {
+            let mut v = Vec::with_capacity(bytes.len() + 1);
+            v.extend_from_slice(bytes);
+            v.push(b'\0');
+            Ok(unsafe { CString::from_vec_with_nul_unchecked(v) })
+        }
+    }
+}
+
+/// Fallible counterpart of [`ModelArgs`], used only by
+/// [`Model::try_new()`](crate::Model::try_new()). Unlike `ModelArgs`, an interior NUL is
+/// rejected with [`NulError`] rather than invoking undefined behavior.
+pub trait TryModelArgs {
+    /// Fallible wrapper of `MeCab::createModel()`.
+    fn try_create_model(self) -> Result<VoidPtr, NulError>;
+}
+
+impl TryModelArgs for &[u8] {
+    fn try_create_model(self) -> Result<VoidPtr, NulError> {
+        let cstr = checked_cstr(self)?;
+        Ok(cstr.as_ref().create_model())
+    }
+}
+
+impl TryModelArgs for &Vec<u8> {
+    #[inline]
+    fn try_create_model(self) -> Result<VoidPtr, NulError> {
+        self.as_slice().try_create_model()
+    }
+}
+
+impl TryModelArgs for Vec<u8> {
+    #[inline]
+    fn try_create_model(self) -> Result<VoidPtr, NulError> {
+        self.as_slice().try_create_model()
+    }
+}
+
+impl TryModelArgs for &str {
+    #[inline]
+    fn try_create_model(self) -> Result<VoidPtr, NulError> {
+        self.as_bytes().try_create_model()
+    }
+}
+
+impl TryModelArgs for &String {
+    #[inline]
+    fn try_create_model(self) -> Result<VoidPtr, NulError> {
+        self.as_str().try_create_model()
+    }
+}
+
+impl TryModelArgs for String {
+    #[inline]
+    fn try_create_model(self) -> Result<VoidPtr, NulError> {
+        self.as_str().try_create_model()
+    }
+}
+
 impl<const N: usize> ModelArgs for &[u8; N] {
     #[inline]
     fn create_model(self) -> VoidPtr {
@@ -304,6 +408,18 @@ pub enum OptionKey {
     InputBufferSize,
     /// Cost factor (int).
     CostFactor,
+    /// Lattice level (int). Deprecated by MeCab in favor of `--marginal`/`--nbest`, but still
+    /// accepted as a way to request the lattice structure needed for N-best/marginal parsing.
+    LatticeLevel,
+    /// N-best enumeration size (int). Equivalent to setting
+    /// [`RequestType::N_BEST`](crate::RequestType::N_BEST) on a [`Lattice`](crate::Lattice).
+    Nbest,
+    /// Temperature parameter for marginal probability computation (float).
+    Theta,
+    /// Output all morphs, including ones not on the best path.
+    AllMorphs,
+    /// Partial parsing mode, respecting boundary/feature constraints on the input.
+    Partial,
 }
 
 impl OptionKey {
@@ -325,6 +441,11 @@ impl OptionKey {
             UnkFeature => b"unk-feature\0",
             InputBufferSize => b"input-buffer-size\0",
             CostFactor => b"cost-factor\0",
+            LatticeLevel => b"lattice-level\0",
+            Nbest => b"nbest\0",
+            Theta => b"theta\0",
+            AllMorphs => b"all-morphs\0",
+            Partial => b"partial\0",
         }
     }
 
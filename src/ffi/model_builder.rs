@@ -0,0 +1,130 @@
+use super::model_args::checked_cstring;
+use super::{Model, NulError, OptionKey};
+
+use std::borrow::Cow;
+use std::ffi::CStr;
+use std::fmt;
+use std::path::Path;
+
+/// Error returned by [`ModelBuilder::build()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModelBuilderError {
+    /// A path or option value contained an interior NUL.
+    Nul(NulError),
+    /// `output_format_type("wakati")` and `node_format(..)` were both set. MeCab silently
+    /// prefers one of them, so `ModelBuilder` rejects the ambiguous configuration instead.
+    WakatiWithNodeFormat,
+    /// `Model::new()` returned `None`. Use
+    /// [`global_error_str()`](crate::global_error_str()) for the cause.
+    ModelInitFailed,
+}
+
+impl fmt::Display for ModelBuilderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Nul(e) => write!(f, "{e}"),
+            Self::WakatiWithNodeFormat => {
+                write!(f, "output_format_type(\"wakati\") conflicts with node_format(..)")
+            }
+            Self::ModelInitFailed => write!(f, "model failed to initialize"),
+        }
+    }
+}
+
+impl std::error::Error for ModelBuilderError {}
+
+/// Typed builder for [`Model`]. It collects options as owned [`CString`](std::ffi::CString)s so
+/// callers do not have to manage `CStr` lifetimes or remember which options take integer,
+/// path, or string values.
+///
+/// ```no_run
+/// use mecab_wrapper::ModelBuilder;
+///
+/// let model = ModelBuilder::new()
+///     .dicdir("/usr/local/mecab/dic/ipadic")
+///     .output_format_type("wakati")
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Default)]
+pub struct ModelBuilder {
+    options: Vec<(OptionKey, Cow<'static, CStr>)>,
+    wakati: bool,
+    node_format: bool,
+    error: Option<NulError>,
+}
+
+impl ModelBuilder {
+    /// Creates an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pushes `key`/`value`, or records the first [`NulError`] encountered (surfaced by
+    /// [`Self::build()`]) if `value` contains an interior NUL.
+    fn push(&mut self, key: OptionKey, value: &[u8]) -> &mut Self {
+        match checked_cstring(value) {
+            Ok(cstring) => self.options.push((key, Cow::Owned(cstring))),
+            Err(e) => {
+                self.error.get_or_insert(e);
+            }
+        }
+        self
+    }
+
+    /// Path of a system dictionary dir. Equivalent to `-d`/`--dicdir`.
+    pub fn dicdir(&mut self, path: impl AsRef<Path>) -> &mut Self {
+        let bytes = path.as_ref().as_os_str().as_encoded_bytes().to_vec();
+        self.push(OptionKey::Dicdir, &bytes)
+    }
+
+    /// Path of a user dictionary file. Equivalent to `-u`/`--userdic`.
+    pub fn userdic(&mut self, path: impl AsRef<Path>) -> &mut Self {
+        let bytes = path.as_ref().as_os_str().as_encoded_bytes().to_vec();
+        self.push(OptionKey::Userdic, &bytes)
+    }
+
+    /// Output format type (e.g. `"wakati"`, `"none"`). Equivalent to `-O`/`--output-format-type`.
+    pub fn output_format_type(&mut self, value: &str) -> &mut Self {
+        self.wakati = value == "wakati";
+        self.push(OptionKey::OutputFormatType, value.as_bytes())
+    }
+
+    /// Max grouping size for unknown words. Equivalent to `--max-grouping-size`.
+    pub fn max_grouping_size(&mut self, value: u32) -> &mut Self {
+        self.push(OptionKey::MaxGroupingSize, value.to_string().as_bytes())
+    }
+
+    /// Input buffer size. Equivalent to `-b`/`--input-buffer-size`.
+    pub fn input_buffer_size(&mut self, value: u32) -> &mut Self {
+        self.push(OptionKey::InputBufferSize, value.to_string().as_bytes())
+    }
+
+    /// Cost factor. Equivalent to `-c`/`--cost-factor`.
+    pub fn cost_factor(&mut self, value: i32) -> &mut Self {
+        self.push(OptionKey::CostFactor, value.to_string().as_bytes())
+    }
+
+    /// User-defined node format. Equivalent to `--node-format`.
+    pub fn node_format(&mut self, value: &str) -> &mut Self {
+        self.node_format = true;
+        self.push(OptionKey::NodeFormat, value.as_bytes())
+    }
+
+    /// Builds a [`Model`] from the collected options.
+    ///
+    /// Returns an error if any option value contained an interior NUL, if
+    /// `output_format_type("wakati")` and `node_format(..)` were both set (MeCab would silently
+    /// ignore one of them), or if [`Model::new()`] fails to initialize.
+    pub fn build(&self) -> Result<Model, ModelBuilderError> {
+        if let Some(e) = self.error {
+            return Err(ModelBuilderError::Nul(e));
+        }
+
+        if self.wakati && self.node_format {
+            return Err(ModelBuilderError::WakatiWithNodeFormat);
+        }
+
+        Model::new(self.options.as_slice()).ok_or(ModelBuilderError::ModelInitFailed)
+    }
+}
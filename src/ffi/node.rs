@@ -1,4 +1,4 @@
-use crate::FeatureReader;
+use crate::{BeginNodesIter, EndNodesIter, FeatureReader, PathIter, PathRevIter};
 
 use libc::{c_char, c_float, c_int, c_long, c_short, c_uchar, c_uint, c_ushort};
 
@@ -93,8 +93,8 @@ pub struct Node {
     /// Backward accumulative log summation. Available only when
     /// [`MARGINAL_PROB`](crate::RequestType::MARGINAL_PROB) is passed.
     pub beta: c_float,
-    /// Marginal probability. Available only when
-    /// [`MARGINAL_PROB`](crate::RequestType::MARGINAL_PROB) is passed.
+    /// Normalized marginal probability of this node belonging to the best path. Available only
+    /// when [`MARGINAL_PROB`](crate::RequestType::MARGINAL_PROB) is passed.
     pub prob: c_float,
     /// Word cost.
     pub wcost: c_short,
@@ -148,7 +148,8 @@ impl Node {
     }
     /// Pointer to the previous node.
     ///
-    /// It returns `None` if the pointer is null.
+    /// It returns `None` if the pointer is null. See [`NodeRevIter`](crate::NodeRevIter) to walk
+    /// the whole lattice backward from [`Lattice::eos_node()`](crate::Lattice::eos_node()).
     pub fn prev(&self) -> Option<&Self> {
         unsafe { self.prev.as_ref() }
     }
@@ -239,9 +240,33 @@ impl Node {
     /// Returns an iterator of [`Node::features()`].
     ///
     /// This is the same as [`FeatureReader::from_node()`].
-    pub fn feature_reader(&self) -> FeatureReader<'_> {
+    pub fn feature_reader(&self) -> FeatureReader<&'_ [u8]> {
         FeatureReader::from_node(self)
     }
+
+    /// Returns an iterator of the nodes starting at the same position as `self`, via
+    /// [`Node::bnext()`]. This is the same as [`BeginNodesIter::from_node()`].
+    pub fn begin_nodes(&self) -> BeginNodesIter<'_> {
+        BeginNodesIter::from_node(self)
+    }
+
+    /// Returns an iterator of the nodes ending at the same position as `self`, via
+    /// [`Node::enext()`]. This is the same as [`EndNodesIter::from_node()`].
+    pub fn end_nodes(&self) -> EndNodesIter<'_> {
+        EndNodesIter::from_node(self)
+    }
+
+    /// Returns an iterator of the right paths of `self`, via [`Node::rpath()`]. This is the
+    /// same as [`PathIter::from_node()`].
+    pub fn right_paths(&self) -> PathIter<'_> {
+        PathIter::from_node(self)
+    }
+
+    /// Returns an iterator of the left paths of `self`, via [`Node::lpath()`]. This is the
+    /// same as [`PathRevIter::from_node()`].
+    pub fn left_paths(&self) -> PathRevIter<'_> {
+        PathRevIter::from_node(self)
+    }
 }
 
 impl Path {
@@ -23,6 +23,20 @@ impl RequestType {
     pub(crate) fn to_int(self) -> u8 {
         self.0
     }
+
+    /// Returns true if `self` has all of the flags set in `other`.
+    ///
+    /// ```
+    /// use mecab_wrapper::RequestType;
+    ///
+    /// let req = RequestType::N_BEST | RequestType::MARGINAL_PROB;
+    /// assert!(req.contains(RequestType::N_BEST));
+    /// assert!(!req.contains(RequestType::PARTIAL));
+    /// ```
+    #[inline]
+    pub fn contains(self, other: Self) -> bool {
+        self & other == other
+    }
 }
 
 impl BitAnd for RequestType {
@@ -1,4 +1,4 @@
-use super::Lattice;
+use super::{Lattice, NBestIter, RequestType};
 
 use libc::c_char;
 use libc::c_void;
@@ -37,6 +37,20 @@ impl<'a> Tagger<'a> {
         unsafe { parse(self.void_tagger.as_ptr(), lattice.as_mut_ptr()) }
     }
 
+    /// Parses `lattice` in N-best mode and returns an [`NBestIter`] over successive best paths,
+    /// or `None` if parsing failed (check [`Self::error_str()`] for the cause).
+    ///
+    /// This sets [`RequestType::N_BEST`] on `lattice` before parsing, so any request type the
+    /// caller already set is preserved alongside it. See [`Lattice::iter_nbest()`] and
+    /// [`NBestIter`] for the iteration and invalidation semantics.
+    pub fn parse_nbest<'b>(&self, lattice: &'b mut Lattice) -> Option<NBestIter<'b>> {
+        lattice.add_request_type(RequestType::N_BEST);
+        if !self.parse(lattice) {
+            return None;
+        }
+        Some(lattice.iter_nbest())
+    }
+
     pub fn error(&self) -> &[u8] {
         unsafe {
             let e = tagger_what(self.void_tagger.as_ptr());
@@ -220,7 +220,35 @@ pub use ffi::*;
 #[cfg(feature = "cmecab")]
 mod node_iter;
 #[cfg(feature = "cmecab")]
+pub use node_iter::BeginNodesIter;
+#[cfg(feature = "cmecab")]
+pub use node_iter::EndNodesIter;
+#[cfg(feature = "cmecab")]
 pub use node_iter::NodeIter;
+#[cfg(feature = "cmecab")]
+pub use node_iter::NodeRevIter;
+#[cfg(feature = "cmecab")]
+pub use node_iter::PathIter;
+#[cfg(feature = "cmecab")]
+pub use node_iter::PathRevIter;
+
+#[cfg(feature = "cmecab")]
+mod morpheme;
+#[cfg(feature = "cmecab")]
+pub use morpheme::Morpheme;
+#[cfg(feature = "cmecab")]
+pub use morpheme::MorphemeIter;
+
+#[cfg(feature = "cmecab")]
+mod owned;
+#[cfg(feature = "cmecab")]
+pub use owned::OwnedLattice;
+#[cfg(feature = "cmecab")]
+pub use owned::OwnedNode;
+#[cfg(feature = "cmecab")]
+pub use owned::OwnedNodeStatus;
+#[cfg(feature = "cmecab")]
+pub use owned::OwnedPath;
 
 mod feat;
 pub use feat::Feature;
@@ -0,0 +1,123 @@
+use crate::ffi::{Node, NodeStatus};
+use crate::NodeIter;
+
+const POS: usize = 0;
+const POS_SUB1: usize = 1;
+const POS_SUB2: usize = 2;
+const POS_SUB3: usize = 3;
+const CONJUGATION_TYPE: usize = 4;
+const CONJUGATION_FORM: usize = 5;
+const BASE_FORM: usize = 6;
+const READING: usize = 7;
+const PRONUNCIATION: usize = 8;
+
+/// A [`Node`], with its IPADIC feature columns parsed out by field.
+///
+/// Obtained from [`NodeIter::morphemes()`]. Accessors return `None` both for MeCab's `*`
+/// ("unspecified") placeholder and for unknown-word nodes whose feature line has fewer columns
+/// than IPADIC's.
+///
+/// BOS/EOS/EoNbest nodes (see [`NodeStatus`]) have an empty surface; use
+/// [`Morpheme::is_boundary()`] to filter them out.
+#[derive(Debug, Clone, Copy)]
+pub struct Morpheme<'a> {
+    node: &'a Node,
+}
+
+impl<'a> Morpheme<'a> {
+    fn from_node(node: &'a Node) -> Self {
+        Self { node }
+    }
+
+    /// The underlying node.
+    #[inline]
+    pub fn node(self) -> &'a Node {
+        self.node
+    }
+
+    /// Surface form.
+    #[inline]
+    pub fn surface(self) -> &'a [u8] {
+        self.node.surface()
+    }
+
+    /// True for the virtual BOS/EOS/EoNbest nodes marking a sentence boundary, which have no
+    /// surface form or meaningful features.
+    #[inline]
+    pub fn is_boundary(self) -> bool {
+        !matches!(self.node.status(), NodeStatus::Normal | NodeStatus::Unknown)
+    }
+
+    fn feature(self, idx: usize) -> Option<&'a str> {
+        let field = self.node.features_str().ok()?.split(',').nth(idx)?;
+        if field == "*" {
+            None
+        } else {
+            Some(field)
+        }
+    }
+
+    /// Part-of-speech (feature column 0).
+    pub fn pos(self) -> Option<&'a str> {
+        self.feature(POS)
+    }
+
+    /// Part-of-speech subcategory 1 (feature column 1).
+    pub fn pos_sub1(self) -> Option<&'a str> {
+        self.feature(POS_SUB1)
+    }
+
+    /// Part-of-speech subcategory 2 (feature column 2).
+    pub fn pos_sub2(self) -> Option<&'a str> {
+        self.feature(POS_SUB2)
+    }
+
+    /// Part-of-speech subcategory 3 (feature column 3).
+    pub fn pos_sub3(self) -> Option<&'a str> {
+        self.feature(POS_SUB3)
+    }
+
+    /// Conjugation type (feature column 4).
+    pub fn conjugation_type(self) -> Option<&'a str> {
+        self.feature(CONJUGATION_TYPE)
+    }
+
+    /// Conjugation form (feature column 5).
+    pub fn conjugation_form(self) -> Option<&'a str> {
+        self.feature(CONJUGATION_FORM)
+    }
+
+    /// Base (dictionary) form (feature column 6).
+    pub fn base_form(self) -> Option<&'a str> {
+        self.feature(BASE_FORM)
+    }
+
+    /// Reading (feature column 7).
+    pub fn reading(self) -> Option<&'a str> {
+        self.feature(READING)
+    }
+
+    /// Pronunciation (feature column 8).
+    pub fn pronunciation(self) -> Option<&'a str> {
+        self.feature(PRONUNCIATION)
+    }
+}
+
+/// Iterator of [`Morpheme`]s. Obtained from [`NodeIter::morphemes()`].
+pub struct MorphemeIter<'a> {
+    inner: NodeIter<'a>,
+}
+
+impl<'a> MorphemeIter<'a> {
+    pub(crate) fn new(inner: NodeIter<'a>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<'a> Iterator for MorphemeIter<'a> {
+    type Item = Morpheme<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(Morpheme::from_node)
+    }
+}
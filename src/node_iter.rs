@@ -1,4 +1,6 @@
-use crate::ffi::{Lattice, Node};
+use crate::ffi::{Lattice, Node, Path};
+
+use std::iter::FusedIterator;
 
 /// Iterates nodes forward.
 ///
@@ -56,6 +58,8 @@ impl<'a> Iterator for NodeIter<'a> {
     }
 }
 
+impl<'a> FusedIterator for NodeIter<'a> {}
+
 impl<'a> NodeIter<'a> {
     /// Returns the current node (= the node which the next [`Iterator::next()`] will return).
     ///
@@ -148,6 +152,13 @@ impl<'a> NodeIter<'a> {
         let node = lattice.bos_node();
         Self { node }
     }
+
+    /// Adapts `self` into an iterator of [`Morpheme`](crate::Morpheme), which exposes the
+    /// node's IPADIC feature columns (POS, base form, reading, ...) already split out.
+    #[inline]
+    pub fn morphemes(self) -> crate::MorphemeIter<'a> {
+        crate::MorphemeIter::new(self)
+    }
 }
 
 /// Iterates nodes backward.
@@ -206,6 +217,8 @@ impl<'a> Iterator for NodeRevIter<'a> {
     }
 }
 
+impl<'a> FusedIterator for NodeRevIter<'a> {}
+
 impl<'a> NodeRevIter<'a> {
     /// Returns the current node (= the node which the next [`Iterator::next()`] will return).
     ///
@@ -299,3 +312,231 @@ impl<'a> NodeRevIter<'a> {
         Self { node }
     }
 }
+
+/// Iterates nodes that start at the same position, via [`Node::bnext()`].
+///
+/// ```no_run
+/// # use mecab_wrapper::Node;
+/// # use mecab_wrapper::BeginNodesIter;
+/// # fn test(node: &Node) {
+/// for n in BeginNodesIter::from_node(node) {
+///     println!("{n:?}");
+/// }
+/// # }
+/// ```
+pub struct BeginNodesIter<'a> {
+    node: Option<&'a Node>,
+}
+
+impl<'a> Iterator for BeginNodesIter<'a> {
+    type Item = &'a Node;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.node?;
+        self.node = node.bnext();
+        Some(node)
+    }
+}
+
+impl<'a> FusedIterator for BeginNodesIter<'a> {}
+
+impl<'a> BeginNodesIter<'a> {
+    /// Returns the current node (= the node which the next [`Iterator::next()`] will return).
+    #[inline]
+    pub fn get_node(&self) -> Option<&'a Node> {
+        self.node
+    }
+
+    /// Initializes with `None`. The returned iterator works like [`Empty`](std::iter::Empty).
+    #[inline]
+    pub fn none() -> Self {
+        Self { node: None }
+    }
+
+    /// Initializes with the given node.
+    #[inline]
+    pub fn from_node(node: &'a Node) -> Self {
+        Self { node: Some(node) }
+    }
+
+    /// This is identical with [`Self::from_node()`] or [`Self::none()`] depending on the
+    /// `node` is `Some` or not.
+    #[inline]
+    pub fn from_node_option(node: Option<&'a Node>) -> Self {
+        Self { node }
+    }
+}
+
+/// Iterates nodes that end at the same position, via [`Node::enext()`].
+///
+/// ```no_run
+/// # use mecab_wrapper::Node;
+/// # use mecab_wrapper::EndNodesIter;
+/// # fn test(node: &Node) {
+/// for n in EndNodesIter::from_node(node) {
+///     println!("{n:?}");
+/// }
+/// # }
+/// ```
+pub struct EndNodesIter<'a> {
+    node: Option<&'a Node>,
+}
+
+impl<'a> Iterator for EndNodesIter<'a> {
+    type Item = &'a Node;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.node?;
+        self.node = node.enext();
+        Some(node)
+    }
+}
+
+impl<'a> FusedIterator for EndNodesIter<'a> {}
+
+impl<'a> EndNodesIter<'a> {
+    /// Returns the current node (= the node which the next [`Iterator::next()`] will return).
+    #[inline]
+    pub fn get_node(&self) -> Option<&'a Node> {
+        self.node
+    }
+
+    /// Initializes with `None`. The returned iterator works like [`Empty`](std::iter::Empty).
+    #[inline]
+    pub fn none() -> Self {
+        Self { node: None }
+    }
+
+    /// Initializes with the given node.
+    #[inline]
+    pub fn from_node(node: &'a Node) -> Self {
+        Self { node: Some(node) }
+    }
+
+    /// This is identical with [`Self::from_node()`] or [`Self::none()`] depending on the
+    /// `node` is `Some` or not.
+    #[inline]
+    pub fn from_node_option(node: Option<&'a Node>) -> Self {
+        Self { node }
+    }
+}
+
+/// Iterates right paths of a node, via [`Path::rnext()`].
+///
+/// ```no_run
+/// # use mecab_wrapper::Node;
+/// # use mecab_wrapper::PathIter;
+/// # fn test(node: &Node) {
+/// for path in PathIter::from_node(node) {
+///     println!("{}", path.cost);
+/// }
+/// # }
+/// ```
+pub struct PathIter<'a> {
+    path: Option<&'a Path>,
+}
+
+impl<'a> Iterator for PathIter<'a> {
+    type Item = &'a Path;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let path = self.path?;
+        self.path = path.rnext();
+        Some(path)
+    }
+}
+
+impl<'a> FusedIterator for PathIter<'a> {}
+
+impl<'a> PathIter<'a> {
+    /// Returns the current path (= the path which the next [`Iterator::next()`] will return).
+    #[inline]
+    pub fn get_path(&self) -> Option<&'a Path> {
+        self.path
+    }
+
+    /// Initializes with `None`. The returned iterator works like [`Empty`](std::iter::Empty).
+    #[inline]
+    pub fn none() -> Self {
+        Self { path: None }
+    }
+
+    /// Initializes with the given path.
+    #[inline]
+    pub fn from_path(path: &'a Path) -> Self {
+        Self { path: Some(path) }
+    }
+
+    /// This is identical with [`Self::from_path()`] or [`Self::none()`] depending on the
+    /// `path` is `Some` or not.
+    #[inline]
+    pub fn from_path_option(path: Option<&'a Path>) -> Self {
+        Self { path }
+    }
+
+    /// Initializes with the given node's [`Node::rpath()`].
+    #[inline]
+    pub fn from_node(node: &'a Node) -> Self {
+        Self::from_path_option(node.rpath())
+    }
+}
+
+/// Iterates left paths of a node, via [`Path::lnext()`].
+///
+/// ```no_run
+/// # use mecab_wrapper::Node;
+/// # use mecab_wrapper::PathRevIter;
+/// # fn test(node: &Node) {
+/// for path in PathRevIter::from_node(node) {
+///     println!("{}", path.cost);
+/// }
+/// # }
+/// ```
+pub struct PathRevIter<'a> {
+    path: Option<&'a Path>,
+}
+
+impl<'a> Iterator for PathRevIter<'a> {
+    type Item = &'a Path;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let path = self.path?;
+        self.path = path.lnext();
+        Some(path)
+    }
+}
+
+impl<'a> FusedIterator for PathRevIter<'a> {}
+
+impl<'a> PathRevIter<'a> {
+    /// Returns the current path (= the path which the next [`Iterator::next()`] will return).
+    #[inline]
+    pub fn get_path(&self) -> Option<&'a Path> {
+        self.path
+    }
+
+    /// Initializes with `None`. The returned iterator works like [`Empty`](std::iter::Empty).
+    #[inline]
+    pub fn none() -> Self {
+        Self { path: None }
+    }
+
+    /// Initializes with the given path.
+    #[inline]
+    pub fn from_path(path: &'a Path) -> Self {
+        Self { path: Some(path) }
+    }
+
+    /// This is identical with [`Self::from_path()`] or [`Self::none()`] depending on the
+    /// `path` is `Some` or not.
+    #[inline]
+    pub fn from_path_option(path: Option<&'a Path>) -> Self {
+        Self { path }
+    }
+
+    /// Initializes with the given node's [`Node::lpath()`].
+    #[inline]
+    pub fn from_node(node: &'a Node) -> Self {
+        Self::from_path_option(node.lpath())
+    }
+}
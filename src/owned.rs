@@ -0,0 +1,208 @@
+use crate::ffi::{Lattice, Node, NodeStatus, RequestType};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Owned counterpart of [`NodeStatus`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OwnedNodeStatus {
+    /// Normal node defined in the dictionary.
+    Normal,
+    /// Unknown node not defined in the dictionary.
+    Unknown,
+    /// Virtual node representing a beginning of the sentence.
+    Bos,
+    /// Virtual node representing an end of the sentence.
+    Eos,
+    /// Virtual node representing an end of the N-best enumeration.
+    EoNbest,
+}
+
+impl From<NodeStatus> for OwnedNodeStatus {
+    fn from(status: NodeStatus) -> Self {
+        match status {
+            NodeStatus::Normal => Self::Normal,
+            NodeStatus::Unknown => Self::Unknown,
+            NodeStatus::Bos => Self::Bos,
+            NodeStatus::Eos => Self::Eos,
+            NodeStatus::EoNbest => Self::EoNbest,
+        }
+    }
+}
+
+/// Deep-copied, owned snapshot of a [`Node`]. Unlike `Node`, this does not borrow from the
+/// `Lattice`/`Tagger` that produced it, and can be serialized (see the `serde` feature).
+///
+/// `alpha`/`beta`/`prob` are `None` unless the lattice was parsed with
+/// [`MARGINAL_PROB`](crate::RequestType::MARGINAL_PROB) set.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OwnedNode {
+    /// Unique node ID, stable within the snapshot it was taken from. Used to key
+    /// [`OwnedPath::lnode_id`]/[`OwnedPath::rnode_id`].
+    pub id: u32,
+    /// Surface form.
+    pub surface: Vec<u8>,
+    /// Feature string.
+    pub features: Vec<u8>,
+    /// Unique part of speech ID.
+    pub posid: u16,
+    /// Right attribute ID.
+    pub rattr: u16,
+    /// Left attribute ID.
+    pub lattr: u16,
+    /// Character type.
+    pub char_type: u8,
+    /// Node status.
+    pub status: OwnedNodeStatus,
+    /// Word cost.
+    pub wcost: i16,
+    /// Best accumulative cost from the BOS node to this node.
+    pub cost: i64,
+    /// Forward accumulative log summation. See [`OwnedNode`] for when this is populated.
+    pub alpha: Option<f32>,
+    /// Backward accumulative log summation. See [`OwnedNode`] for when this is populated.
+    pub beta: Option<f32>,
+    /// Marginal probability. See [`OwnedNode`] for when this is populated.
+    pub prob: Option<f32>,
+}
+
+impl OwnedNode {
+    /// Deep-copies `node`. `marginal` should reflect whether the originating lattice had
+    /// [`RequestType::MARGINAL_PROB`] set, since a node's `alpha`/`beta`/`prob` read as `0.0`
+    /// both when the mode is off and, legitimately, when it's on.
+    fn from_node(node: &Node, marginal: bool) -> Self {
+        Self {
+            id: node.id,
+            surface: node.surface().to_vec(),
+            features: node.features().to_vec(),
+            posid: node.posid,
+            rattr: node.rattr.0,
+            lattr: node.lattr.0,
+            char_type: node.char_type,
+            status: node.status().into(),
+            wcost: node.wcost,
+            cost: node.cost,
+            alpha: marginal.then_some(node.alpha),
+            beta: marginal.then_some(node.beta),
+            prob: marginal.then_some(node.prob),
+        }
+    }
+}
+
+/// Deep-copied edge between two [`OwnedNode`]s, by [`OwnedNode::id`]. Mirrors [`Path`](crate::Path).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OwnedPath {
+    /// [`OwnedNode::id`] of the left node of this path.
+    pub lnode_id: u32,
+    /// [`OwnedNode::id`] of the right node of this path.
+    pub rnode_id: u32,
+    /// Local cost.
+    pub cost: i32,
+    /// Marginal probability.
+    pub prob: f32,
+}
+
+/// Owned, serializable snapshot of a [`Lattice`]: its nodes (see
+/// [`OwnedLattice::from_lattice()`] for which ones) plus the path connectivity between them,
+/// deep-copied so it can outlive the `Lattice`/`Tagger`/`Model` that produced it,
+/// be cached to disk, or sent over the wire (see the `serde` feature).
+///
+/// Round-trips losslessly through any `serde` format, both binary (e.g. `bincode`) and textual
+/// (e.g. JSON):
+///
+/// ```
+/// use mecab_wrapper::{OwnedLattice, OwnedNode, OwnedNodeStatus, OwnedPath};
+///
+/// let bos = OwnedNode {
+///     id: 0,
+///     surface: Vec::new(),
+///     features: Vec::new(),
+///     posid: 0,
+///     rattr: 0,
+///     lattr: 0,
+///     char_type: 0,
+///     status: OwnedNodeStatus::Bos,
+///     wcost: 0,
+///     cost: 0,
+///     alpha: None,
+///     beta: None,
+///     prob: None,
+/// };
+/// let word = OwnedNode {
+///     id: 1,
+///     surface: b"abc".to_vec(),
+///     features: b"noun,*,*,*,*,*,abc,*,*".to_vec(),
+///     posid: 38,
+///     rattr: 5,
+///     lattr: 5,
+///     char_type: 2,
+///     status: OwnedNodeStatus::Normal,
+///     wcost: 100,
+///     cost: 100,
+///     alpha: None,
+///     beta: None,
+///     prob: None,
+/// };
+/// let snapshot = OwnedLattice {
+///     nodes: vec![bos.clone(), word.clone()],
+///     paths: vec![OwnedPath { lnode_id: bos.id, rnode_id: word.id, cost: 100, prob: 0.0 }],
+/// };
+///
+/// # #[cfg(feature = "serde")]
+/// # {
+/// let json = serde_json::to_string(&snapshot).unwrap();
+/// let roundtripped: OwnedLattice = serde_json::from_str(&json).unwrap();
+/// assert_eq!(snapshot, roundtripped);
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OwnedLattice {
+    /// Nodes of the lattice, keyed by [`OwnedNode::id`], in the order
+    /// [`Node::begin_nodes()`](crate::Node::begin_nodes()) yields them from each node on the best
+    /// path from [`Lattice::bos_node()`]. See [`OwnedLattice::from_lattice()`] for which nodes
+    /// are not included.
+    pub nodes: Vec<OwnedNode>,
+    /// Path connectivity between nodes, keyed by [`OwnedNode::id`].
+    pub paths: Vec<OwnedPath>,
+}
+
+impl OwnedLattice {
+    /// Deep-copies `lattice` into an owned snapshot.
+    ///
+    /// [`Lattice::iter_nodes()`] only follows the best-path chain (via [`Node::next()`]), so it
+    /// misses every node that wasn't chosen for the best path even though [`Path::lnode()`]/
+    /// [`Path::rnode()`] can still point at them. This walks the best path via `iter_nodes()` and,
+    /// from each best-path node, fans out over [`Node::begin_nodes()`] to pick up the nodes that
+    /// follow it in its `bnext` chain, with their right paths. Nodes that begin at a position
+    /// where no best-path node begins, and nodes ahead of the best-path node in its `bnext`
+    /// chain, are not included, so `nodes`/`paths` can cover less than the whole lattice graph.
+    pub fn from_lattice(lattice: &mut Lattice<'_>) -> Self {
+        let marginal = lattice.get_request_type().contains(RequestType::MARGINAL_PROB);
+
+        let mut nodes = Vec::new();
+        let mut paths = Vec::new();
+
+        for position in lattice.iter_nodes() {
+            for node in position.begin_nodes() {
+                nodes.push(OwnedNode::from_node(node, marginal));
+
+                for path in node.right_paths() {
+                    if let (Some(lnode), Some(rnode)) = (path.lnode(), path.rnode()) {
+                        paths.push(OwnedPath {
+                            lnode_id: lnode.id,
+                            rnode_id: rnode.id,
+                            cost: path.cost,
+                            prob: path.prob,
+                        });
+                    }
+                }
+            }
+        }
+
+        Self { nodes, paths }
+    }
+}